@@ -0,0 +1,156 @@
+use std::collections::{HashMap, HashSet};
+
+use pyo3::prelude::*;
+
+use crate::{extract_pod_info, PodInfo};
+
+/// Per-pod occurrence stats across a sequence of daily snapshots: how many
+/// days it showed up, the first/last day it was seen, and how many times
+/// it reappeared after being absent the day before (a present -> absent ->
+/// present flap).
+#[pyclass]
+pub struct PodOccurrence {
+    #[pyo3(get)]
+    pod: PodInfo,
+    #[pyo3(get)]
+    days_seen: usize,
+    #[pyo3(get)]
+    first_seen_day: usize,
+    #[pyo3(get)]
+    last_seen_day: usize,
+    #[pyo3(get)]
+    flap_count: usize,
+}
+
+/// Builds the per-pod occurrence index for `analyze_pod_history`, folding
+/// one daily snapshot in at a time rather than holding every day's full set
+/// in memory at once.
+struct PodOccurrenceBuilder {
+    days_seen: usize,
+    first_seen_day: usize,
+    last_seen_day: usize,
+    flap_count: usize,
+    present_yesterday: bool,
+}
+
+/// Ingests daily pod snapshots and reports, per pod, how many days it
+/// appeared and how many times it flapped (present -> absent -> present).
+/// Each day's snapshot is deduped before tallying, so a caller passing the
+/// same pod twice in one day's list doesn't inflate its occurrence count.
+#[pyfunction]
+pub fn analyze_pod_history(snapshots: Vec<Vec<Bound<'_, PyAny>>>) -> PyResult<Vec<PodOccurrence>> {
+    let mut index: HashMap<PodInfo, PodOccurrenceBuilder> = HashMap::new();
+
+    for (day, snapshot) in snapshots.iter().enumerate() {
+        // Dedup within the day first: a duplicate dict entry for the same
+        // pod must count as one day of presence, not one per occurrence.
+        let mut present_today: HashSet<PodInfo> = HashSet::with_capacity(snapshot.len());
+        for obj in snapshot {
+            present_today.insert(extract_pod_info(obj)?);
+        }
+
+        for pod in &present_today {
+            let is_new = !index.contains_key(pod);
+            let entry = index.entry(pod.clone()).or_insert_with(|| PodOccurrenceBuilder {
+                days_seen: 0,
+                first_seen_day: day,
+                last_seen_day: day,
+                flap_count: 0,
+                present_yesterday: false,
+            });
+
+            // A pod re-appearing after being absent on a prior day is a
+            // present -> absent -> present flap.
+            if !is_new && !entry.present_yesterday {
+                entry.flap_count += 1;
+            }
+
+            entry.days_seen += 1;
+            entry.last_seen_day = day;
+            entry.present_yesterday = true;
+        }
+
+        // Any pod tracked from a prior day but absent today stops being
+        // "present yesterday" for tomorrow's flap detection.
+        for (pod, entry) in index.iter_mut() {
+            if !present_today.contains(pod) {
+                entry.present_yesterday = false;
+            }
+        }
+    }
+
+    Ok(index
+        .into_iter()
+        .map(|(pod, entry)| PodOccurrence {
+            pod,
+            days_seen: entry.days_seen,
+            first_seen_day: entry.first_seen_day,
+            last_seen_day: entry.last_seen_day,
+            flap_count: entry.flap_count,
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pyo3::types::PyDict;
+
+    fn pod_dict<'py>(py: Python<'py>, cluster: &str, namespace: &str, pod: &str) -> Bound<'py, PyAny> {
+        let dict = PyDict::new(py);
+        dict.set_item("cluster", cluster).unwrap();
+        dict.set_item("namespace", namespace).unwrap();
+        dict.set_item("pod", pod).unwrap();
+        dict.into_any()
+    }
+
+    fn find<'a>(occurrences: &'a [PodOccurrence], pod: &str) -> &'a PodOccurrence {
+        occurrences.iter().find(|o| o.pod.pod == pod).expect("pod not in occurrence index")
+    }
+
+    #[test]
+    fn counts_consecutive_presence_with_no_flap() {
+        Python::with_gil(|py| {
+            let snapshots = vec![
+                vec![pod_dict(py, "c1", "ns", "a")],
+                vec![pod_dict(py, "c1", "ns", "a")],
+                vec![pod_dict(py, "c1", "ns", "a")],
+            ];
+            let result = analyze_pod_history(snapshots).unwrap();
+            let a = find(&result, "a");
+            assert_eq!(a.days_seen, 3);
+            assert_eq!(a.first_seen_day, 0);
+            assert_eq!(a.last_seen_day, 2);
+            assert_eq!(a.flap_count, 0);
+        });
+    }
+
+    #[test]
+    fn counts_a_flap_when_a_pod_reappears_after_an_absence() {
+        Python::with_gil(|py| {
+            let snapshots = vec![
+                vec![pod_dict(py, "c1", "ns", "a")],
+                vec![],
+                vec![pod_dict(py, "c1", "ns", "a")],
+            ];
+            let result = analyze_pod_history(snapshots).unwrap();
+            let a = find(&result, "a");
+            assert_eq!(a.days_seen, 2);
+            assert_eq!(a.flap_count, 1);
+            assert_eq!(a.last_seen_day, 2);
+        });
+    }
+
+    #[test]
+    fn duplicate_entries_in_one_day_count_as_a_single_day() {
+        Python::with_gil(|py| {
+            let snapshots = vec![vec![
+                pod_dict(py, "c1", "ns", "a"),
+                pod_dict(py, "c1", "ns", "a"),
+            ]];
+            let result = analyze_pod_history(snapshots).unwrap();
+            let a = find(&result, "a");
+            assert_eq!(a.days_seen, 1);
+        });
+    }
+}
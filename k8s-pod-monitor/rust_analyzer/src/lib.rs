@@ -1,25 +1,65 @@
 use pyo3::prelude::*;
-use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
-#[derive(Serialize, Deserialize, Debug, Clone, Hash, Eq, PartialEq)]
-struct PodInfo { cluster: String, namespace: String, pod: String }
-#[derive(Serialize, Deserialize, Debug)]
-struct AnalysisResult { new: Vec<PodInfo>, ongoing: Vec<PodInfo>, resolved: Vec<PodInfo> }
+
+mod watch;
+use watch::start_pod_watcher;
+
+mod history;
+use history::{analyze_pod_history, PodOccurrence};
+
+#[pyclass]
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+pub struct PodInfo {
+    #[pyo3(get)]
+    pub(crate) cluster: String,
+    #[pyo3(get)]
+    pub(crate) namespace: String,
+    #[pyo3(get)]
+    pub(crate) pod: String,
+}
+
+#[pyclass]
+struct AnalysisResult {
+    #[pyo3(get)]
+    new: Vec<PodInfo>,
+    #[pyo3(get)]
+    ongoing: Vec<PodInfo>,
+    #[pyo3(get)]
+    resolved: Vec<PodInfo>,
+}
+
+/// Pulls `cluster`/`namespace`/`pod` out of a dict or any other mapping
+/// object, so callers can pass plain dicts without a `PodInfo` constructor.
+pub(crate) fn extract_pod_info(obj: &Bound<'_, PyAny>) -> PyResult<PodInfo> {
+    Ok(PodInfo {
+        cluster: obj.get_item("cluster")?.extract()?,
+        namespace: obj.get_item("namespace")?.extract()?,
+        pod: obj.get_item("pod")?.extract()?,
+    })
+}
+
 #[pyfunction]
-fn analyze_pod_changes(today_pods_str: String, yesterday_pods_str: String) -> PyResult<String> {
-    let today_pods: Vec<PodInfo> = serde_json::from_str(&today_pods_str).map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
-    let yesterday_pods: Vec<PodInfo> = serde_json::from_str(&yesterday_pods_str).map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
-    let today_set: HashSet<PodInfo> = today_pods.into_iter().collect();
-    let yesterday_set: HashSet<PodInfo> = yesterday_pods.into_iter().collect();
-    let result = AnalysisResult {
+fn analyze_pod_changes(
+    today_pods: Vec<Bound<'_, PyAny>>,
+    yesterday_pods: Vec<Bound<'_, PyAny>>,
+) -> PyResult<AnalysisResult> {
+    let today_set: HashSet<PodInfo> = today_pods.iter().map(extract_pod_info).collect::<PyResult<_>>()?;
+    let yesterday_set: HashSet<PodInfo> = yesterday_pods.iter().map(extract_pod_info).collect::<PyResult<_>>()?;
+
+    Ok(AnalysisResult {
         new: today_set.difference(&yesterday_set).cloned().collect(),
         ongoing: today_set.intersection(&yesterday_set).cloned().collect(),
         resolved: yesterday_set.difference(&today_set).cloned().collect(),
-    };
-    serde_json::to_string(&result).map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+    })
 }
+
 #[pymodule]
-fn rust_analyzer(_py: Python, m: &PyModule) -> PyResult<()> {
+fn rust_analyzer(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PodInfo>()?;
+    m.add_class::<AnalysisResult>()?;
+    m.add_class::<PodOccurrence>()?;
     m.add_function(wrap_pyfunction!(analyze_pod_changes, m)?)?;
+    m.add_function(wrap_pyfunction!(start_pod_watcher, m)?)?;
+    m.add_function(wrap_pyfunction!(analyze_pod_history, m)?)?;
     Ok(())
 }
@@ -0,0 +1,133 @@
+use std::collections::HashSet;
+use std::thread;
+
+use futures::StreamExt;
+use k8s_openapi::api::core::v1::Pod;
+use kube::{
+    api::{Api, ResourceExt},
+    runtime::{watcher, WatchStreamExt},
+    Client,
+};
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::PodInfo;
+
+/// Converts a watched `Pod` into the same `PodInfo` shape used by the
+/// snapshot-diffing functions, so callers get a consistent view regardless
+/// of whether pods arrived via a one-shot diff or the live watch.
+fn pod_info(pod: &Pod) -> PodInfo {
+    PodInfo {
+        cluster: std::env::var("CLUSTER_NAME").unwrap_or_default(),
+        namespace: pod.namespace().unwrap_or_default(),
+        pod: pod.name_any(),
+    }
+}
+
+/// Invokes the Python callback with `(event_type, pod)`, logging (rather
+/// than propagating) any exception so one bad callback can't kill the watch.
+fn invoke_callback(py: Python<'_>, callback: &PyObject, event_type: &str, pod: &PodInfo) {
+    let dict = PyDict::new(py);
+    let _ = dict.set_item("cluster", &pod.cluster);
+    let _ = dict.set_item("namespace", &pod.namespace);
+    let _ = dict.set_item("pod", &pod.pod);
+
+    if let Err(e) = callback.call1(py, (event_type, dict)) {
+        e.print(py);
+    }
+}
+
+/// Runs the watch loop for a single namespace (or all namespaces when
+/// `namespace` is `None`). Consumes the raw `watcher::Event` stream rather
+/// than `.touched_objects()` alone so a relist (`Init`/`InitApply`/`InitDone`,
+/// fired after a reconnect or `410 Gone`) can be diffed against `seen`:
+/// anything tracked before the relist but absent from the fresh object set
+/// is reported `resolved`, which also covers pods force-deleted while
+/// disconnected (no interim `Delete` event would otherwise be observed).
+async fn watch_namespace(client: Client, namespace: Option<String>, callback: PyObject) {
+    let api: Api<Pod> = match &namespace {
+        Some(ns) => Api::namespaced(client, ns),
+        None => Api::all(client),
+    };
+
+    let mut seen: HashSet<PodInfo> = HashSet::new();
+    let mut relist_buffer: HashSet<PodInfo> = HashSet::new();
+    let mut stream = watcher(api, watcher::Config::default()).default_backoff();
+
+    while let Some(event) = stream.next().await {
+        let event = match event {
+            Ok(event) => event,
+            Err(e) => {
+                eprintln!("pod watch error: {}", e);
+                continue;
+            }
+        };
+
+        match event {
+            watcher::Event::Init => relist_buffer.clear(),
+            watcher::Event::InitApply(pod) => {
+                relist_buffer.insert(pod_info(&pod));
+            }
+            watcher::Event::InitDone => {
+                for info in seen.difference(&relist_buffer).cloned().collect::<Vec<_>>() {
+                    Python::with_gil(|py| invoke_callback(py, &callback, "resolved", &info));
+                }
+                for info in relist_buffer.difference(&seen).cloned().collect::<Vec<_>>() {
+                    Python::with_gil(|py| invoke_callback(py, &callback, "new", &info));
+                }
+                seen = std::mem::take(&mut relist_buffer);
+            }
+            watcher::Event::Apply(pod) => {
+                let info = pod_info(&pod);
+                if seen.insert(info.clone()) {
+                    Python::with_gil(|py| invoke_callback(py, &callback, "new", &info));
+                }
+            }
+            watcher::Event::Delete(pod) => {
+                let info = pod_info(&pod);
+                if seen.remove(&info) {
+                    Python::with_gil(|py| invoke_callback(py, &callback, "resolved", &info));
+                }
+            }
+        }
+    }
+}
+
+/// Spawns a background watcher over `namespaces` (empty means all
+/// namespaces) that calls `callback(event_type, pod_dict)` for every
+/// `new`/`resolved` pod change it observes. Runs on its own OS thread with
+/// its own tokio runtime, since this is invoked from a Python call site
+/// with no surrounding async context to spawn onto.
+#[pyfunction]
+pub fn start_pod_watcher(namespaces: Vec<String>, callback: PyObject) -> PyResult<()> {
+    thread::spawn(move || {
+        let runtime = match tokio::runtime::Runtime::new() {
+            Ok(runtime) => runtime,
+            Err(e) => {
+                eprintln!("failed to start pod watcher runtime: {}", e);
+                return;
+            }
+        };
+
+        runtime.block_on(async move {
+            let client = match Client::try_default().await {
+                Ok(client) => client,
+                Err(e) => {
+                    eprintln!("failed to create kube client: {}", e);
+                    return;
+                }
+            };
+
+            if namespaces.is_empty() {
+                watch_namespace(client, None, callback).await;
+            } else {
+                let handles = namespaces.into_iter().map(|ns| {
+                    tokio::spawn(watch_namespace(client.clone(), Some(ns), callback.clone()))
+                });
+                futures::future::join_all(handles).await;
+            }
+        });
+    });
+
+    Ok(())
+}
@@ -1,56 +1,81 @@
-use kube::{Client, api::{Api, ResourceExt}};
-use k8s_openapi::api::core::v1::Node;
+use kube::Client;
 use anyhow::Result;
 
-#[tokio::main]
-async fn main() -> Result<()> {
-    let client = Client::try_default().await?;
-    let nodes: Api<Node> = Api::all(client);
-    let node_list = nodes.list(&Default::default()).await?;
+mod quantity;
+mod stats;
+mod metrics;
 
-    for node in node_list.items {
-        let name = node.name_any();
-        let status = node.status.unwrap_or_default();
-        let capacity = status.capacity.unwrap_or_default();
-        let allocatable = status.allocatable.unwrap_or_default();
+use quantity::format_count;
+use stats::{fetch_node_stats, NodeStats};
+
+/// Allocatable as a percentage of capacity, or `None` when capacity is 0.
+fn utilization_pct(capacity: f64, allocatable: f64) -> Option<f64> {
+    if capacity == 0.0 {
+        None
+    } else {
+        Some((allocatable / capacity) * 100.0)
+    }
+}
+
+fn format_pct(pct: Option<f64>) -> String {
+    match pct {
+        Some(pct) => format!("{:.1}%", pct),
+        None => "-".to_string(),
+    }
+}
 
-        println!("🖥️ Node: {}", name);
+fn print_report(stats: &[NodeStats]) {
+    for node in stats {
+        println!("🖥️ Node: {}", node.name);
         println!("-----------------------------------");
 
-        // CPU
         println!("📦 CPU:");
-        println!("  Capacity:    {}", capacity.get("cpu").map_or("-", |q| q.0.as_str()));
-        println!("  Allocatable: {}", allocatable.get("cpu").map_or("-", |q| q.0.as_str()));
+        println!("  Capacity:    {}", node.cpu_capacity_raw);
+        println!("  Allocatable: {}", node.cpu_allocatable_raw);
+        println!("  Utilization: {}", format_pct(utilization_pct(node.cpu_capacity, node.cpu_allocatable)));
         println!();
 
-        // Memory
         println!("💾 Memory:");
-        println!("  Capacity:    {}", capacity.get("memory").map_or("-", |q| q.0.as_str()));
-        println!("  Allocatable: {}", allocatable.get("memory").map_or("-", |q| q.0.as_str()));
+        println!("  Capacity:    {}", node.memory_capacity_raw);
+        println!("  Allocatable: {}", node.memory_allocatable_raw);
+        println!("  Utilization: {}", format_pct(utilization_pct(node.memory_capacity, node.memory_allocatable)));
         println!();
 
-        // GPU
         println!("🎮 GPU:");
-        println!("  Capacity:    {}", capacity.get("nvidia.com/gpu").map_or("0", |q| q.0.as_str()));
-        println!("  Allocatable: {}", allocatable.get("nvidia.com/gpu").map_or("0", |q| q.0.as_str()));
+        println!("  Capacity:    {}", format_count(node.gpu_capacity));
+        println!("  Allocatable: {}", format_count(node.gpu_allocatable));
+        println!("  Utilization: {}", format_pct(utilization_pct(node.gpu_capacity, node.gpu_allocatable)));
         println!();
 
-        // MIG GPU 리소스
         println!("🔹 MIG GPU Instances:");
-        for (key, val) in capacity.iter() {
-            if key.starts_with("nvidia.com/mig") {
-                let alloc_val = allocatable.get(key);
-                println!(
-                    "  {} -> Capacity: {}, Allocatable: {}",
-                    key,
-                    val.0,
-                    alloc_val.map_or("0", |q| q.0.as_str())
-                );
-            }
+        for mig in &node.mig {
+            println!(
+                "  nvidia.com/mig-{} -> Capacity: {}, Allocatable: {} ({})",
+                mig.profile,
+                format_count(mig.capacity),
+                format_count(mig.allocatable),
+                format_pct(utilization_pct(mig.capacity, mig.allocatable))
+            );
         }
 
         println!("\n===============================\n");
     }
+}
+
+/// Runs the one-shot stdout report, or a long-running `/metrics` server when
+/// `METRICS_ADDR` is set (e.g. `METRICS_ADDR=0.0.0.0:9100`).
+#[tokio::main]
+async fn main() -> Result<()> {
+    let client = Client::try_default().await?;
+
+    if let Ok(addr) = std::env::var("METRICS_ADDR") {
+        let addr = addr.parse()?;
+        metrics::serve(addr, client).await?;
+        return Ok(());
+    }
+
+    let node_stats = fetch_node_stats(client).await?;
+    print_report(&node_stats);
 
     Ok(())
 }
@@ -0,0 +1,173 @@
+use std::convert::Infallible;
+use std::fmt::Write as _;
+use std::net::SocketAddr;
+
+use anyhow::Result;
+use hyper::service::{make_service_fn, service_fn};
+use hyper::{Body, Method, Request, Response, Server, StatusCode};
+use kube::Client;
+
+use crate::quantity::format_count;
+use crate::stats::{fetch_node_stats, NodeStats};
+
+/// Renders node stats as Prometheus text exposition format.
+pub fn render_prometheus(stats: &[NodeStats]) -> String {
+    let mut out = String::new();
+
+    let _ = writeln!(out, "# HELP k8s_node_cpu_capacity Node CPU capacity in cores");
+    let _ = writeln!(out, "# TYPE k8s_node_cpu_capacity gauge");
+    for node in stats {
+        let _ = writeln!(out, "k8s_node_cpu_capacity{{node=\"{}\"}} {}", node.name, node.cpu_capacity);
+    }
+
+    let _ = writeln!(out, "# HELP k8s_node_cpu_allocatable Node CPU allocatable in cores");
+    let _ = writeln!(out, "# TYPE k8s_node_cpu_allocatable gauge");
+    for node in stats {
+        let _ = writeln!(out, "k8s_node_cpu_allocatable{{node=\"{}\"}} {}", node.name, node.cpu_allocatable);
+    }
+
+    let _ = writeln!(out, "# HELP k8s_node_memory_capacity_bytes Node memory capacity in bytes");
+    let _ = writeln!(out, "# TYPE k8s_node_memory_capacity_bytes gauge");
+    for node in stats {
+        let _ = writeln!(out, "k8s_node_memory_capacity_bytes{{node=\"{}\"}} {}", node.name, node.memory_capacity);
+    }
+
+    let _ = writeln!(out, "# HELP k8s_node_memory_allocatable_bytes Node memory allocatable in bytes");
+    let _ = writeln!(out, "# TYPE k8s_node_memory_allocatable_bytes gauge");
+    for node in stats {
+        let _ = writeln!(out, "k8s_node_memory_allocatable_bytes{{node=\"{}\"}} {}", node.name, node.memory_allocatable);
+    }
+
+    let _ = writeln!(out, "# HELP k8s_node_gpu_capacity Node GPU capacity count");
+    let _ = writeln!(out, "# TYPE k8s_node_gpu_capacity gauge");
+    for node in stats {
+        let _ = writeln!(out, "k8s_node_gpu_capacity{{node=\"{}\"}} {}", node.name, format_count(node.gpu_capacity));
+    }
+
+    let _ = writeln!(out, "# HELP k8s_node_gpu_allocatable Node GPU allocatable count");
+    let _ = writeln!(out, "# TYPE k8s_node_gpu_allocatable gauge");
+    for node in stats {
+        let _ = writeln!(out, "k8s_node_gpu_allocatable{{node=\"{}\"}} {}", node.name, format_count(node.gpu_allocatable));
+    }
+
+    let _ = writeln!(out, "# HELP k8s_node_mig_capacity Node MIG GPU capacity count by profile");
+    let _ = writeln!(out, "# TYPE k8s_node_mig_capacity gauge");
+    for node in stats {
+        for mig in &node.mig {
+            let _ = writeln!(
+                out,
+                "k8s_node_mig_capacity{{node=\"{}\",profile=\"{}\"}} {}",
+                node.name, mig.profile, format_count(mig.capacity)
+            );
+        }
+    }
+
+    let _ = writeln!(out, "# HELP k8s_node_mig_allocatable Node MIG GPU allocatable count by profile");
+    let _ = writeln!(out, "# TYPE k8s_node_mig_allocatable gauge");
+    for node in stats {
+        for mig in &node.mig {
+            let _ = writeln!(
+                out,
+                "k8s_node_mig_allocatable{{node=\"{}\",profile=\"{}\"}} {}",
+                node.name, mig.profile, format_count(mig.allocatable)
+            );
+        }
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stats::MigStats;
+
+    fn node_fixture() -> NodeStats {
+        NodeStats {
+            name: "node-a".to_string(),
+            cpu_capacity: 4.0,
+            cpu_allocatable: 3.5,
+            cpu_capacity_raw: "4".to_string(),
+            cpu_allocatable_raw: "3500m".to_string(),
+            memory_capacity: 34359738368.0,
+            memory_allocatable: 32000000000.0,
+            memory_capacity_raw: "32Gi".to_string(),
+            memory_allocatable_raw: "32000000000".to_string(),
+            gpu_capacity: 2.0,
+            gpu_allocatable: 2.0,
+            mig: vec![MigStats {
+                profile: "1g.5gb".to_string(),
+                capacity: 7.0,
+                allocatable: 7.0,
+            }],
+        }
+    }
+
+    #[test]
+    fn renders_help_and_type_lines_for_each_metric() {
+        let out = render_prometheus(&[node_fixture()]);
+        assert!(out.contains("# HELP k8s_node_cpu_capacity Node CPU capacity in cores"));
+        assert!(out.contains("# TYPE k8s_node_cpu_capacity gauge"));
+        assert!(out.contains("# HELP k8s_node_memory_capacity_bytes Node memory capacity in bytes"));
+        assert!(out.contains("# TYPE k8s_node_memory_capacity_bytes gauge"));
+    }
+
+    #[test]
+    fn renders_node_labeled_gauge_values() {
+        let out = render_prometheus(&[node_fixture()]);
+        assert!(out.contains("k8s_node_cpu_capacity{node=\"node-a\"} 4"));
+        assert!(out.contains("k8s_node_cpu_allocatable{node=\"node-a\"} 3.5"));
+        assert!(out.contains("k8s_node_memory_capacity_bytes{node=\"node-a\"} 34359738368"));
+        assert!(out.contains("k8s_node_gpu_capacity{node=\"node-a\"} 2"));
+    }
+
+    #[test]
+    fn renders_mig_lines_with_profile_label() {
+        let out = render_prometheus(&[node_fixture()]);
+        assert!(out.contains("k8s_node_mig_capacity{node=\"node-a\",profile=\"1g.5gb\"} 7"));
+        assert!(out.contains("k8s_node_mig_allocatable{node=\"node-a\",profile=\"1g.5gb\"} 7"));
+    }
+
+    #[test]
+    fn renders_only_headers_for_an_empty_node_list() {
+        let out = render_prometheus(&[]);
+        assert!(out.contains("# HELP k8s_node_cpu_capacity"));
+        assert!(!out.contains("node=\""));
+    }
+}
+
+/// Only `GET /metrics` is handled; everything else 404s. Re-lists nodes on
+/// every call rather than caching, so request latency tracks cluster size.
+async fn handle(client: Client, req: Request<Body>) -> Result<Response<Body>, Infallible> {
+    if req.method() != Method::GET || req.uri().path() != "/metrics" {
+        return Ok(Response::builder()
+            .status(StatusCode::NOT_FOUND)
+            .body(Body::from("not found"))
+            .unwrap());
+    }
+
+    match fetch_node_stats(client).await {
+        Ok(stats) => Ok(Response::builder()
+            .status(StatusCode::OK)
+            .header("Content-Type", "text/plain; version=0.0.4")
+            .body(Body::from(render_prometheus(&stats)))
+            .unwrap()),
+        Err(e) => Ok(Response::builder()
+            .status(StatusCode::INTERNAL_SERVER_ERROR)
+            .body(Body::from(format!("failed to list nodes: {}", e)))
+            .unwrap()),
+    }
+}
+
+/// Binds `addr` and serves until the process is killed; runs forever, no
+/// shutdown signal handling.
+pub async fn serve(addr: SocketAddr, client: Client) -> Result<()> {
+    let make_svc = make_service_fn(move |_conn| {
+        let client = client.clone();
+        async move { Ok::<_, Infallible>(service_fn(move |req| handle(client.clone(), req))) }
+    });
+
+    println!("📈 serving /metrics on http://{}", addr);
+    Server::bind(&addr).serve(make_svc).await?;
+    Ok(())
+}
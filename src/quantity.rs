@@ -0,0 +1,166 @@
+use std::fmt;
+
+/// Errors that can occur while parsing a Kubernetes resource quantity string.
+#[derive(Debug)]
+pub enum QuantityError {
+    Empty,
+    InvalidNumber(String),
+    Negative(String),
+}
+
+impl fmt::Display for QuantityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            QuantityError::Empty => write!(f, "empty quantity string"),
+            QuantityError::InvalidNumber(s) => write!(f, "invalid quantity: {}", s),
+            QuantityError::Negative(s) => write!(f, "negative quantity not allowed: {}", s),
+        }
+    }
+}
+
+impl std::error::Error for QuantityError {}
+
+/// Parses a Kubernetes resource quantity string (e.g. `"500m"`, `"4Gi"`, `"2"`)
+/// into a normalized `f64`: cores for CPU, bytes for memory, whole counts for
+/// GPU/MIG. Binary suffixes (`Ki,Mi,Gi,Ti,Pi,Ei`) are powers of 1024, decimal
+/// suffixes (`k,M,G,T,P,E`) are powers of 1000, and `m`/`u`/`n` scale the
+/// mantissa down by milli/micro/nano (so `"500m"` -> `0.5`).
+pub fn parse_quantity(raw: &str) -> Result<f64, QuantityError> {
+    let raw = raw.trim();
+    if raw.is_empty() {
+        return Err(QuantityError::Empty);
+    }
+
+    let (mantissa, suffix) = split_suffix(raw);
+    let value: f64 = mantissa
+        .parse()
+        .map_err(|_| QuantityError::InvalidNumber(raw.to_string()))?;
+
+    let scaled = match suffix {
+        "" => value,
+        "n" => value / 1e9,
+        "u" => value / 1e6,
+        "m" => value / 1e3,
+        "k" => value * 1e3,
+        "M" => value * 1e6,
+        "G" => value * 1e9,
+        "T" => value * 1e12,
+        "P" => value * 1e15,
+        "E" => value * 1e18,
+        "Ki" => value * 2f64.powi(10),
+        "Mi" => value * 2f64.powi(20),
+        "Gi" => value * 2f64.powi(30),
+        "Ti" => value * 2f64.powi(40),
+        "Pi" => value * 2f64.powi(50),
+        "Ei" => value * 2f64.powi(60),
+        _ => return Err(QuantityError::InvalidNumber(raw.to_string())),
+    };
+
+    if scaled < 0.0 {
+        return Err(QuantityError::Negative(raw.to_string()));
+    }
+
+    Ok(scaled)
+}
+
+/// Parses a quantity key that may be absent, treating a missing key as `0`.
+pub fn parse_quantity_or_zero(raw: Option<&str>) -> f64 {
+    raw.and_then(|s| parse_quantity(s).ok()).unwrap_or(0.0)
+}
+
+/// Splits a raw quantity string into its numeric mantissa and unit suffix,
+/// recognizing the longest valid k8s suffix first (`Ki` before `k`).
+fn split_suffix(raw: &str) -> (&str, &str) {
+    const BINARY_SUFFIXES: [&str; 6] = ["Ki", "Mi", "Gi", "Ti", "Pi", "Ei"];
+    for suffix in BINARY_SUFFIXES {
+        if let Some(mantissa) = raw.strip_suffix(suffix) {
+            return (mantissa, suffix);
+        }
+    }
+
+    const SINGLE_CHAR_SUFFIXES: [&str; 9] = ["n", "u", "m", "k", "M", "G", "T", "P", "E"];
+    for suffix in SINGLE_CHAR_SUFFIXES {
+        if let Some(mantissa) = raw.strip_suffix(suffix) {
+            return (mantissa, suffix);
+        }
+    }
+
+    (raw, "")
+}
+
+/// Formats a normalized count (GPU/MIG) without a trailing `.0` when it's whole.
+pub fn format_count(value: f64) -> String {
+    if value.fract() == 0.0 {
+        format!("{}", value as i64)
+    } else {
+        format!("{:.2}", value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_bare_numbers() {
+        assert_eq!(parse_quantity("2").unwrap(), 2.0);
+        assert_eq!(parse_quantity("1.5").unwrap(), 1.5);
+    }
+
+    #[test]
+    fn parses_scientific_mantissa() {
+        assert_eq!(parse_quantity("1e3").unwrap(), 1000.0);
+    }
+
+    #[test]
+    fn parses_decimal_suffixes() {
+        assert_eq!(parse_quantity("1k").unwrap(), 1_000.0);
+        assert_eq!(parse_quantity("1M").unwrap(), 1_000_000.0);
+        assert_eq!(parse_quantity("1G").unwrap(), 1_000_000_000.0);
+    }
+
+    #[test]
+    fn parses_binary_suffixes() {
+        assert_eq!(parse_quantity("1Ki").unwrap(), 1024.0);
+        assert_eq!(parse_quantity("4Gi").unwrap(), 4.0 * 2f64.powi(30));
+    }
+
+    #[test]
+    fn binary_suffix_takes_precedence_over_decimal() {
+        // "Ki" must be read as binary (1024), not as decimal kilo "k".
+        assert_eq!(parse_quantity("2Ki").unwrap(), 2.0 * 1024.0);
+    }
+
+    #[test]
+    fn parses_milli_micro_nano() {
+        assert_eq!(parse_quantity("500m").unwrap(), 0.5);
+        assert_eq!(parse_quantity("500u").unwrap(), 0.0005);
+        assert_eq!(parse_quantity("500n").unwrap(), 0.0000005);
+    }
+
+    #[test]
+    fn rejects_negative_values() {
+        assert!(matches!(parse_quantity("-1"), Err(QuantityError::Negative(_))));
+    }
+
+    #[test]
+    fn rejects_garbage_input() {
+        assert!(matches!(parse_quantity("abc"), Err(QuantityError::InvalidNumber(_))));
+    }
+
+    #[test]
+    fn rejects_empty_input() {
+        assert!(matches!(parse_quantity(""), Err(QuantityError::Empty)));
+    }
+
+    #[test]
+    fn missing_key_parses_as_zero() {
+        assert_eq!(parse_quantity_or_zero(None), 0.0);
+    }
+
+    #[test]
+    fn format_count_keeps_whole_gpu_counts_exact() {
+        assert_eq!(format_count(4.0), "4");
+        assert_eq!(format_count(1.5), "1.50");
+    }
+}
@@ -0,0 +1,80 @@
+use k8s_openapi::api::core::v1::Node;
+use kube::{api::{Api, ResourceExt}, Client};
+use anyhow::Result;
+
+use crate::quantity::parse_quantity_or_zero;
+
+/// A single MIG GPU profile's capacity/allocatable counts, e.g.
+/// `nvidia.com/mig-1g.5gb`.
+pub struct MigStats {
+    pub profile: String,
+    pub capacity: f64,
+    pub allocatable: f64,
+}
+
+/// Normalized capacity/allocatable figures for one node, ready to be
+/// rendered as Prometheus gauges. The `_raw` strings keep the original
+/// quantity text (e.g. `"32Gi"`) for the human-readable stdout report,
+/// since a byte count is a lot less readable than the k8s quantity it
+/// was parsed from.
+pub struct NodeStats {
+    pub name: String,
+    pub cpu_capacity: f64,
+    pub cpu_allocatable: f64,
+    pub cpu_capacity_raw: String,
+    pub cpu_allocatable_raw: String,
+    pub memory_capacity: f64,
+    pub memory_allocatable: f64,
+    pub memory_capacity_raw: String,
+    pub memory_allocatable_raw: String,
+    pub gpu_capacity: f64,
+    pub gpu_allocatable: f64,
+    pub mig: Vec<MigStats>,
+}
+
+fn raw_quantity(val: Option<&k8s_openapi::apimachinery::pkg::api::resource::Quantity>) -> String {
+    val.map_or_else(|| "-".to_string(), |q| q.0.clone())
+}
+
+/// Lists nodes via the kube API and normalizes their resource quantities.
+pub async fn fetch_node_stats(client: Client) -> Result<Vec<NodeStats>> {
+    let nodes: Api<Node> = Api::all(client);
+    let node_list = nodes.list(&Default::default()).await?;
+
+    let mut stats = Vec::with_capacity(node_list.items.len());
+    for node in node_list.items {
+        let name = node.name_any();
+        let status = node.status.unwrap_or_default();
+        let capacity = status.capacity.unwrap_or_default();
+        let allocatable = status.allocatable.unwrap_or_default();
+
+        let mut mig = Vec::new();
+        for (key, val) in capacity.iter() {
+            if let Some(profile) = key.strip_prefix("nvidia.com/mig-") {
+                let alloc_val = allocatable.get(key);
+                mig.push(MigStats {
+                    profile: profile.to_string(),
+                    capacity: parse_quantity_or_zero(Some(val.0.as_str())),
+                    allocatable: parse_quantity_or_zero(alloc_val.map(|q| q.0.as_str())),
+                });
+            }
+        }
+
+        stats.push(NodeStats {
+            name,
+            cpu_capacity: parse_quantity_or_zero(capacity.get("cpu").map(|q| q.0.as_str())),
+            cpu_allocatable: parse_quantity_or_zero(allocatable.get("cpu").map(|q| q.0.as_str())),
+            cpu_capacity_raw: raw_quantity(capacity.get("cpu")),
+            cpu_allocatable_raw: raw_quantity(allocatable.get("cpu")),
+            memory_capacity: parse_quantity_or_zero(capacity.get("memory").map(|q| q.0.as_str())),
+            memory_allocatable: parse_quantity_or_zero(allocatable.get("memory").map(|q| q.0.as_str())),
+            memory_capacity_raw: raw_quantity(capacity.get("memory")),
+            memory_allocatable_raw: raw_quantity(allocatable.get("memory")),
+            gpu_capacity: parse_quantity_or_zero(capacity.get("nvidia.com/gpu").map(|q| q.0.as_str())),
+            gpu_allocatable: parse_quantity_or_zero(allocatable.get("nvidia.com/gpu").map(|q| q.0.as_str())),
+            mig,
+        });
+    }
+
+    Ok(stats)
+}